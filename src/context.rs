@@ -0,0 +1,191 @@
+use crate::{Doom, Location, Stack, Top};
+
+/// Extends `Result<T, Top<D>>` and `Result<T, Stack>` with ergonomic context-propagation
+/// methods, removing most of the explicit `match`/`map_err` boilerplate otherwise needed to
+/// build up a [`Stack`] as an error travels up the call chain.
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::{here, Description, Doom, DoomResultExt, Top};
+///
+/// struct DidNotWork;
+///
+/// impl Doom for DidNotWork {
+///     fn tag(&self) -> &'static str {
+///         "DidNotWork"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("Did not work")
+///     }
+/// }
+///
+/// struct GiveUp;
+///
+/// impl Doom for GiveUp {
+///     fn tag(&self) -> &'static str {
+///         "GiveUp"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("Gave up")
+///     }
+/// }
+///
+/// fn might_fail() -> Result<(), Top<DidNotWork>> {
+///     DidNotWork.fail()
+/// }
+///
+/// fn call_site() -> Result<(), Top<GiveUp>> {
+///     might_fail().context(GiveUp).spot(here!())
+/// }
+///
+/// assert!(call_site().is_err());
+/// ```
+pub trait DoomResultExt<T> {
+    /// Maps the `Err` variant by pushing `doom` on top of it (see [`Stack::push`] and
+    /// [`Top::push`]).
+    fn context<P>(self, doom: P) -> Result<T, Top<P>>
+    where
+        P: Doom;
+
+    /// Maps the `Err` variant by [`spot`](Top::spot)-ting `location` on it.
+    fn spot(self, location: Location) -> Self;
+
+    /// Syntax sugar for [`DoomResultExt::context`], then [`DoomResultExt::spot`].
+    fn pot<P>(self, doom: P, location: Location) -> Result<T, Top<P>>
+    where
+        P: Doom;
+}
+
+impl<T, D> DoomResultExt<T> for Result<T, Top<D>>
+where
+    D: Doom,
+{
+    fn context<P>(self, doom: P) -> Result<T, Top<P>>
+    where
+        P: Doom,
+    {
+        self.map_err(|top| top.push(doom))
+    }
+
+    fn spot(self, location: Location) -> Self {
+        self.map_err(|top| top.spot(location))
+    }
+
+    fn pot<P>(self, doom: P, location: Location) -> Result<T, Top<P>>
+    where
+        P: Doom,
+    {
+        self.map_err(|top| top.pot(doom, location))
+    }
+}
+
+impl<T> DoomResultExt<T> for Result<T, Stack> {
+    fn context<P>(self, doom: P) -> Result<T, Top<P>>
+    where
+        P: Doom,
+    {
+        self.map_err(|stack| stack.push(doom))
+    }
+
+    fn spot(self, location: Location) -> Self {
+        self.map_err(|stack| stack.spot(location))
+    }
+
+    fn pot<P>(self, doom: P, location: Location) -> Result<T, Top<P>>
+    where
+        P: Doom,
+    {
+        self.map_err(|stack| stack.pot(doom, location))
+    }
+}
+
+/// Returns early with `doom.fail()` (see [`Doom::fail`]) unless `condition` holds.
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::{ensure, Description, Doom, Top};
+///
+/// struct NotEven;
+///
+/// impl Doom for NotEven {
+///     fn tag(&self) -> &'static str {
+///         "NotEven"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("The number provided is not even")
+///     }
+/// }
+///
+/// fn checked_half(n: u32) -> Result<u32, Top<NotEven>> {
+///     ensure!(n % 2 == 0, NotEven);
+///     Ok(n / 2)
+/// }
+///
+/// assert!(checked_half(3).is_err());
+/// assert_eq!(checked_half(4).unwrap(), 2);
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($condition:expr, $doom:expr) => {
+        if !($condition) {
+            return $crate::Doom::fail($doom);
+        }
+    };
+}
+
+/// Returns early with `doom.fail()` (see [`Doom::fail`]).
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::{bail, Description, Doom, Top};
+///
+/// struct GiveUp;
+///
+/// impl Doom for GiveUp {
+///     fn tag(&self) -> &'static str {
+///         "GiveUp"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("Gave up")
+///     }
+/// }
+///
+/// fn always_fails() -> Result<(), Top<GiveUp>> {
+///     bail!(GiveUp);
+/// }
+///
+/// assert!(always_fails().is_err());
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($doom:expr) => {
+        return $crate::Doom::fail($doom);
+    };
+}
+
+/// Like [`ensure!`], but returns early with `doom.fail_as_stack()` (see
+/// [`Doom::fail_as_stack`]) instead.
+#[macro_export]
+macro_rules! ensure_stack {
+    ($condition:expr, $doom:expr) => {
+        if !($condition) {
+            return $crate::Doom::fail_as_stack($doom);
+        }
+    };
+}
+
+/// Like [`bail!`], but returns early with `doom.fail_as_stack()` (see
+/// [`Doom::fail_as_stack`]) instead.
+#[macro_export]
+macro_rules! bail_stack {
+    ($doom:expr) => {
+        return $crate::Doom::fail_as_stack($doom);
+    };
+}
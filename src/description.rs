@@ -1,4 +1,6 @@
-use std::fmt::{self, Debug, Display, Formatter};
+#[cfg(feature = "no_std")]
+use alloc::string::String;
+use core::fmt::{self, Debug, Display, Formatter};
 
 /// An error description. To maximize efficiency, a `Description` can either be
 /// a `Static` (`&'static str`) or `Owned` (`String`) string. This allows
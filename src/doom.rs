@@ -50,7 +50,7 @@ use crate::{Description, Stack, Top};
 ///
 /// [`doomstack`]: crate
 /// [`Entry`]: crate::Entry
-/// [`Box<dyn Any>`]: std::any::Any
+/// [`Box<dyn Any>`]: core::any::Any
 pub trait Doom: 'static + Sized + Send + Sync {
     /// A short, one-word, statically defined tag, used to identify the error type.
     fn tag(&self) -> &'static str;
@@ -86,7 +86,7 @@ pub trait Doom: 'static + Sized + Send + Sync {
     /// let oupsie = Oupsie(42);
     /// let stack = oupsie.into_stack();
     ///
-    /// let value = stack.entries()[0]
+    /// let value = stack.entries().next().unwrap()
     ///     .original()
     ///     .unwrap()
     ///     .downcast_ref::<Oupsie>()
@@ -96,12 +96,28 @@ pub trait Doom: 'static + Sized + Send + Sync {
     /// ```
     ///
     /// [`Entry`]: crate::Entry
-    /// [`Box<dyn Any>`]: std::any::Any
+    /// [`Box<dyn Any>`]: core::any::Any
     fn keep_original() -> bool {
         false
     }
 
-    /// Wraps `self` into a [`Top<Self>`] whose [`top()`] is `self` and whose [`base()`] has no entries.
+    /// A type-level stand-in for [`Doom::tag()`], used by [`Stack::contains`] to recognize an
+    /// archived [`Entry`] that did not keep its original (see [`Doom::keep_original()`])
+    /// without needing an instance of `Self` to call [`Doom::tag()`] on.
+    ///
+    /// [`Doom::tag()`] is an instance method and can depend on which variant of an enum `self`
+    /// is, so there is no general way to ask "what would `Self`'s tag be?" without an instance.
+    /// Types whose tag never varies (e.g. most unit structs) should override [`Doom::tag_hint`]
+    /// to return `Some` of that fixed tag. The default implementation returns `None`, meaning
+    /// [`Stack::contains`] will only recognize entries of `Self` whose original was kept.
+    ///
+    /// [`Stack::contains`]: crate::Stack::contains
+    /// [`Entry`]: crate::Entry
+    fn tag_hint() -> Option<&'static str> {
+        None
+    }
+
+    /// Wraps `self` into a [`Top<Self>`] whose [`doom()`] is `self` and whose [`stack()`] has no entries.
     /// 
     /// # Examples
     /// 
@@ -124,11 +140,11 @@ pub trait Doom: 'static + Sized + Send + Sync {
     /// let oupsie = Oupsie(42);
     /// let top = oupsie.clone().into_top();
     /// 
-    /// assert_eq!(top.top(), &oupsie);
+    /// assert_eq!(top.doom(), &oupsie);
     /// ```
     ///
-    /// [`top()`]: crate::Top::top
-    /// [`base()`]: crate::Top::base
+    /// [`doom()`]: crate::Top::doom
+    /// [`stack()`]: crate::Top::stack
     fn into_top(self) -> Top<Self> {
         Stack::new().push(self)
     }
@@ -154,7 +170,7 @@ pub trait Doom: 'static + Sized + Send + Sync {
     ///
     /// let stack = Oupsie.into_stack();
     /// 
-    /// assert_eq!(stack.entries()[0].tag(), "Oupsie");
+    /// assert_eq!(stack.entries().next().unwrap().tag(), "Oupsie");
     /// ```
     ///
     /// [`Entry`]: crate::Entry
@@ -1,8 +1,13 @@
 use crate::{Description, Doom, Location};
-use std::{
+#[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+use std::backtrace::Backtrace;
+#[cfg(feature = "no_std")]
+use alloc::sync::Arc;
+#[cfg(not(feature = "no_std"))]
+use std::sync::Arc;
+use core::{
     any::Any,
     fmt::{self, Debug, Display, Formatter},
-    sync::Arc,
 };
 
 /// An [`Entry`] is an element of an error [`Stack`], archiving a [`Doom`] error.
@@ -30,7 +35,7 @@ use std::{
 ///         Description::Owned(format!("Made a mess: {}", self.details))
 ///     }
 ///
-///     fn keep_original(&self) -> bool {
+///     fn keep_original() -> bool {
 ///         true
 ///     }
 /// }
@@ -66,6 +71,10 @@ pub struct Entry {
     description: Description,
     location: Option<Location>,
     original: Option<Arc<dyn Any + Send + Sync>>,
+    // Wrapped in an `Arc` (rather than the bare `Option<Backtrace>` one might expect) so that
+    // `Entry` can keep deriving `Clone`: `Backtrace` itself is not `Clone`.
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    backtrace: Option<Arc<Backtrace>>,
 }
 
 impl Entry {
@@ -80,9 +89,67 @@ impl Entry {
             description: Doom::description(&doom),
             location: None,
             original: None,
+            #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+            backtrace: Self::capture_backtrace(),
         };
 
-        if doom.keep_original() {
+        if D::keep_original() {
+            entry.original = Some(Arc::new(doom));
+        }
+
+        entry
+    }
+
+    /// Builds an [`Entry`] directly out of its parts, bypassing [`Doom`].
+    ///
+    /// Used to archive errors that do not implement [`Doom`] (e.g. foreign
+    /// [`std::error::Error`] values, see [`Stack::from_error`]).
+    ///
+    /// [`Stack::from_error`]: crate::Stack::from_error
+    pub(crate) fn from_parts(
+        tag: &'static str,
+        description: Description,
+        original: Option<Arc<dyn Any + Send + Sync>>,
+    ) -> Self {
+        Entry {
+            tag,
+            description,
+            location: None,
+            original,
+            #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+            backtrace: Self::capture_backtrace(),
+        }
+    }
+
+    /// Captures a [`Backtrace`], honoring the standard `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`
+    /// environment gating (see [`Backtrace::capture`]).
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    fn capture_backtrace() -> Option<Arc<Backtrace>> {
+        Some(Arc::new(Backtrace::capture()))
+    }
+
+    /// Archives a [`Doom`] error like [`Entry::archive`], but reuses an already-captured
+    /// [`Backtrace`] instead of capturing a new one.
+    ///
+    /// Used when converting a [`Top`] into a [`Stack`] (see `impl From<Top<D>> for Stack`), so
+    /// that the resulting [`Entry`]'s [`Backtrace`] still points at wherever the [`Top`] was
+    /// created, not wherever it was later converted.
+    ///
+    /// [`Top`]: crate::Top
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    pub(crate) fn archive_with_backtrace<D>(doom: D, backtrace: Option<Arc<Backtrace>>) -> Self
+    where
+        D: Doom,
+    {
+        let mut entry = Entry {
+            tag: doom.tag(),
+            description: Doom::description(&doom),
+            location: None,
+            original: None,
+            backtrace,
+        };
+
+        if D::keep_original() {
             entry.original = Some(Arc::new(doom));
         }
 
@@ -120,6 +187,15 @@ impl Entry {
     pub fn spot(&mut self, location: Location) {
         self.location = Some(location);
     }
+
+    /// Returns the [`Backtrace`] captured when the [`Entry`] was archived, if any.
+    ///
+    /// Only available with the `backtrace` feature enabled, which requires `std` and is
+    /// therefore incompatible with the `no_std` feature.
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
 }
 
 impl Display for Entry {
@@ -136,6 +212,11 @@ impl Debug for Entry {
             write!(f, "[{}] {}", self.tag, self.description)?;
         }
 
+        #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+        if let Some(backtrace) = self.backtrace() {
+            write!(f, "\n{backtrace}")?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,32 @@
+use crate::Stack;
+#[cfg(not(feature = "no_std"))]
+use std::error::Error;
+#[cfg(feature = "no_std")]
+use core::error::Error;
+
+/// Extends any `std::error::Error` with a convenience conversion into a [`Stack`].
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::DoomErrorExt;
+/// use std::num::ParseIntError;
+///
+/// let error: ParseIntError = "oupsie".parse::<u32>().unwrap_err();
+/// let stack = error.into_doom_stack();
+///
+/// assert_eq!(stack.entries().count(), 1);
+/// ```
+pub trait DoomErrorExt {
+    /// Converts `self` into a [`Stack`], as in [`Stack::from_error`].
+    fn into_doom_stack(self) -> Stack;
+}
+
+impl<E> DoomErrorExt for E
+where
+    E: Error + Send + Sync + 'static,
+{
+    fn into_doom_stack(self) -> Stack {
+        Stack::from_error(self)
+    }
+}
@@ -24,7 +24,7 @@
 #[macro_export]
 macro_rules! here {
     () => {
-        doomstack::Location {
+        $crate::Location {
             file: file!(),
             line: line!(),
         }
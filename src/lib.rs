@@ -1,14 +1,23 @@
+#![cfg_attr(feature = "no_std", no_std)]
+
+#[cfg(feature = "no_std")]
+extern crate alloc;
+
+mod context;
 mod description;
 mod doom;
 mod entry;
+mod error_ext;
 mod here;
 mod location;
 mod stack;
 mod top;
 
+pub use context::DoomResultExt;
 pub use description::Description;
 pub use doom::Doom;
 pub use entry::Entry;
+pub use error_ext::DoomErrorExt;
 pub use location::Location;
-pub use stack::Stack;
+pub use stack::{Stack, StackTail};
 pub use top::Top;
@@ -1,8 +1,23 @@
-use crate::{Doom, Entry, Location, Top};
-use std::{
-    error::Error,
+use crate::{Description, Doom, Entry, Location, Top};
+#[cfg(feature = "no_std")]
+use alloc::{boxed::Box, string::ToString, sync::Arc, vec::Vec};
+#[cfg(not(feature = "no_std"))]
+use std::sync::Arc;
+use core::{
+    any::type_name,
     fmt::{self, Debug, Display, Formatter},
 };
+#[cfg(not(feature = "no_std"))]
+use std::error::Error;
+#[cfg(feature = "no_std")]
+use core::error::Error;
+// In `no_std` builds there is no portable, allocation-only equivalent of `std::sync::OnceLock`,
+// so `core::cell::OnceCell` is used instead; this trades away `Stack`/`StackTail`'s `Sync` bound
+// under the `no_std` feature.
+#[cfg(not(feature = "no_std"))]
+use std::sync::OnceLock;
+#[cfg(feature = "no_std")]
+use core::cell::OnceCell as OnceLock;
 
 /// A stack of [`Entry`]-ies, each archiving a [`Doom`] error.
 ///
@@ -105,6 +120,7 @@ use std::{
 #[derive(Default, Clone)]
 pub struct Stack {
     entries: Vec<Entry>,
+    tail: OnceLock<StackTail>,
 }
 
 impl Stack {
@@ -113,12 +129,108 @@ impl Stack {
         Default::default()
     }
 
+    /// Returns `true` if the [`Stack`] has no [`Entry`]-ies.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
     /// Returns an iterator over the [`Stack`]'s [`Entry`]-ies, top (i.e., most recently
     /// pushed) to bottom (i.e., first pushed).
     pub fn entries(&self) -> impl Iterator<Item = &Entry> {
         self.entries.iter().rev()
     }
 
+    /// Searches the [`Stack`], top to bottom, for the first [`Entry`] whose retained
+    /// original (see [`Doom::keep_original()`]) downcasts to `D`, returning a reference to
+    /// it if found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doomstack::{Description, Doom};
+    ///
+    /// #[derive(Debug, PartialEq)]
+    /// struct Oupsie(u64);
+    ///
+    /// impl Doom for Oupsie {
+    ///     fn tag(&self) -> &'static str {
+    ///         "Oupsie"
+    ///     }
+    ///
+    ///     fn description(&self) -> Description {
+    ///         Description::Static("Made a mess")
+    ///     }
+    ///
+    ///     fn keep_original() -> bool {
+    ///         true
+    ///     }
+    /// }
+    ///
+    /// let stack = Oupsie(42).into_stack();
+    ///
+    /// assert_eq!(stack.downcast_ref::<Oupsie>(), Some(&Oupsie(42)));
+    /// ```
+    pub fn downcast_ref<D>(&self) -> Option<&D>
+    where
+        D: Doom,
+    {
+        self.entries()
+            .find_map(|entry| entry.original()?.downcast_ref::<D>())
+    }
+
+    /// Returns `true` if the [`Stack`] contains an [`Entry`] of type `D`.
+    ///
+    /// An [`Entry`] is recognized as being of type `D` in one of two ways:
+    ///  - If its original error was kept (see [`Doom::keep_original()`]), it is recognized
+    ///    by [`Stack::downcast_ref`], regardless of which variant of `D` it is (this is the
+    ///    same check `downcast_ref` performs, so it works for, e.g., an `enum NetworkError {
+    ///    Timeout, ConnReset }` no matter which variant was archived).
+    ///  - Otherwise, [`Doom::tag_hint()`] is used as a type-level stand-in for `D`'s tag. This
+    ///    only matches [`Entry`]-ies whose tag equals `D::tag_hint()`: for a `Doom` type whose
+    ///    [`Doom::tag()`] varies by variant, an [`Entry`] for a variant other than the one
+    ///    [`Doom::tag_hint()`] describes will not be found this way unless its original was
+    ///    kept. `Doom` types that do not override [`Doom::tag_hint()`] (the default returns
+    ///    `None`) are only ever recognized via the first, `downcast_ref`-based check.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doomstack::{Description, Doom};
+    ///
+    /// struct Timeout;
+    ///
+    /// impl Doom for Timeout {
+    ///     fn tag(&self) -> &'static str {
+    ///         "Timeout"
+    ///     }
+    ///
+    ///     fn description(&self) -> Description {
+    ///         Description::Static("Timed out")
+    ///     }
+    ///
+    ///     fn tag_hint() -> Option<&'static str> {
+    ///         Some("Timeout")
+    ///     }
+    /// }
+    ///
+    /// let stack = Timeout.into_stack();
+    ///
+    /// assert!(stack.contains::<Timeout>());
+    /// ```
+    pub fn contains<D>(&self) -> bool
+    where
+        D: Doom,
+    {
+        if self.downcast_ref::<D>().is_some() {
+            return true;
+        }
+
+        match D::tag_hint() {
+            Some(tag) => self.entries().any(|entry| entry.tag() == tag),
+            None => false,
+        }
+    }
+
     /// Pushes a [`Doom`] error on top of the current [`Stack`], producing a [`Top`].
     ///
     /// The resulting [`Top`] stores the new error as-is: this is useful, e.g., if the
@@ -133,17 +245,31 @@ impl Stack {
     /// Pushes a [`Doom`] error on top of the current [`Stack`], producing a new [`Stack`].
     ///
     /// The resulting [`Stack`] stores the new error as an [`Entry`], in its archived form.
-    pub fn push_as_stack<D>(mut self, doom: D) -> Self
+    pub fn push_as_stack<D>(self, doom: D) -> Self
     where
         D: Doom,
     {
-        self.entries.push(Entry::archive(doom));
+        self.push_entry(Entry::archive(doom))
+    }
+
+    /// Pushes an already-archived [`Entry`] on top of the current [`Stack`].
+    ///
+    /// Used by [`Stack::push_as_stack`] and by `impl From<Top<D>> for Stack`. With the
+    /// `backtrace` feature, the latter archives the [`Top`]'s error itself (reusing its
+    /// already-captured `Backtrace`, if any) rather than going through [`Entry::archive`];
+    /// without it, the latter also uses [`Entry::archive`].
+    ///
+    /// [`Top`]: crate::Top
+    pub(crate) fn push_entry(mut self, entry: Entry) -> Self {
+        self.entries.push(entry);
+        self.tail = OnceLock::new();
         self
     }
 
     /// Sets the last spotting [`Location`] for the *top* [`Entry`] in the [`Stack`].
     pub fn spot(mut self, location: Location) -> Self {
         self.entries.last_mut().unwrap().spot(location);
+        self.tail = OnceLock::new();
         self
     }
 
@@ -156,9 +282,182 @@ impl Stack {
     {
         self.push(doom).spot(location)
     }
+
+    /// Converts any `std::error::Error` into a [`Stack`], archiving it and its entire
+    /// [`source()`] chain.
+    ///
+    /// The top [`Entry`] of the resulting [`Stack`] archives `error` itself, tagged with
+    /// `std::any::type_name::<E>()` and keeping `error` as its original value. Each error
+    /// returned by [`source()`] is archived below it, in turn, tagged `"std::error::Error"`
+    /// (their concrete type is not known), so that the resulting [`Stack`] reads most-recent
+    /// (i.e. `error`) on top, exactly like a hand-built [`Stack`].
+    ///
+    /// See also [`DoomErrorExt::into_doom_stack`], a convenience method built on top of
+    /// [`Stack::from_error`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use doomstack::Stack;
+    /// use std::num::ParseIntError;
+    ///
+    /// let error: ParseIntError = "oupsie".parse::<u32>().unwrap_err();
+    /// let stack = Stack::from_error(error);
+    ///
+    /// assert_eq!(stack.entries().count(), 1);
+    /// ```
+    ///
+    /// [`source()`]: std::error::Error::source
+    /// [`DoomErrorExt::into_doom_stack`]: crate::DoomErrorExt::into_doom_stack
+    pub fn from_error<E>(error: E) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        let mut entries = Vec::new();
+        let mut source = error.source();
+
+        while let Some(source_error) = source {
+            entries.push(Entry::from_parts(
+                "std::error::Error",
+                Description::Owned(source_error.to_string()),
+                None,
+            ));
+
+            source = source_error.source();
+        }
+
+        entries.reverse();
+
+        entries.push(Entry::from_parts(
+            type_name::<E>(),
+            Description::Owned(error.to_string()),
+            Some(Arc::new(error)),
+        ));
+
+        Stack {
+            entries,
+            tail: OnceLock::new(),
+        }
+    }
 }
 
-impl Error for Stack {}
+/// Walking [`Error::source()`] on a [`Stack`] yields one node per archived [`Entry`] below
+/// its top one (the top [`Entry`] is `self`), top to bottom.
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::{Description, Doom};
+/// use std::error::Error;
+///
+/// struct Oupsie;
+///
+/// impl Doom for Oupsie {
+///     fn tag(&self) -> &'static str {
+///         "Oupsie"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("Made a mess")
+///     }
+/// }
+///
+/// let stack = Oupsie
+///     .into_stack()
+///     .push_as_stack(Oupsie)
+///     .push_as_stack(Oupsie);
+///
+/// let mut nodes = 1;
+/// let mut source = Error::source(&stack);
+///
+/// while let Some(error) = source {
+///     nodes += 1;
+///     source = error.source();
+/// }
+///
+/// assert_eq!(nodes, stack.entries().count());
+/// ```
+impl Error for Stack {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        let len = self.entries.len();
+
+        if len <= 1 {
+            return None;
+        }
+
+        let tail = self.tail.get_or_init(|| {
+            let entries = Arc::from(&self.entries[..len - 1]);
+            StackTail::new(entries).expect("`entries` is non-empty")
+        });
+
+        Some(tail)
+    }
+}
+
+/// A view over a (non-empty) suffix of a [`Stack`]'s [`Entry`]-ies, used to expose each
+/// archived [`Entry`] as one node in the [`Error::source()`] chain of a [`Stack`] or [`Top`].
+///
+/// A [`StackTail`] holds its [`Entry`]-ies in an [`Arc`], so cloning a [`StackTail`] is cheap.
+/// Producing the next, shorter [`StackTail`] (see its own [`Error::source()`]) copies the
+/// remaining [`Entry`]-ies into a new [`Arc`]; the storage is not shared between steps. The
+/// result of each step is cached the first time it is requested, so that repeated calls to
+/// [`Error::source()`] do not redo the work.
+///
+/// [`Top`]: crate::Top
+#[derive(Clone)]
+pub struct StackTail {
+    entries: Arc<[Entry]>,
+    tail: OnceLock<Box<StackTail>>,
+}
+
+impl StackTail {
+    fn new(entries: Arc<[Entry]>) -> Option<Self> {
+        if entries.is_empty() {
+            None
+        } else {
+            Some(StackTail {
+                entries,
+                tail: OnceLock::new(),
+            })
+        }
+    }
+
+    fn top(&self) -> &Entry {
+        self.entries
+            .last()
+            .expect("`StackTail` is never constructed empty")
+    }
+}
+
+impl Error for StackTail {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        let len = self.entries.len();
+
+        if len <= 1 {
+            return None;
+        }
+
+        let tail = self.tail.get_or_init(|| {
+            let entries = self.entries[..len - 1].into();
+            Box::new(StackTail::new(entries).expect("`entries` is non-empty"))
+        });
+
+        Some(tail.as_ref())
+    }
+}
+
+impl Display for StackTail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        let top = self.top();
+        write!(f, "[{}] {}", top.tag(), top.description())
+    }
+}
+
+impl Debug for StackTail {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
+        Display::fmt(self, f)
+    }
+}
 
 impl Display for Stack {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), fmt::Error> {
@@ -1,6 +1,14 @@
-use crate::{Doom, Location, Stack};
-use std::{
-    error::Error,
+use crate::{Doom, Entry, Location, Stack};
+#[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+use std::backtrace::Backtrace;
+#[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+use std::sync::Arc;
+#[cfg(not(feature = "no_std"))]
+use std::error::Error;
+#[cfg(feature = "no_std")]
+use core::error::Error;
+use core::{
+    any::Any,
     fmt::{self, Debug, Display, Formatter},
 };
 
@@ -9,6 +17,10 @@ pub struct Top<D: Doom> {
     doom: D,
     location: Option<Location>,
     stack: Stack,
+    // Wrapped in an `Arc` (rather than the bare `Option<Backtrace>` one might expect) so that
+    // `Top` can keep deriving `Clone`: `Backtrace` itself is not `Clone`.
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    backtrace: Option<Arc<Backtrace>>,
 }
 
 impl<D> Top<D>
@@ -20,9 +32,20 @@ where
             doom,
             location: None,
             stack,
+            #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+            backtrace: Some(Arc::new(Backtrace::capture())),
         }
     }
 
+    /// Returns the [`Backtrace`] captured when the [`Top`] was created, if any.
+    ///
+    /// Only available with the `backtrace` feature enabled, which requires `std` and is
+    /// therefore incompatible with the `no_std` feature.
+    #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+    pub fn backtrace(&self) -> Option<&Backtrace> {
+        self.backtrace.as_deref()
+    }
+
     pub fn doom(&self) -> &D {
         &self.doom
     }
@@ -35,6 +58,15 @@ where
         &self.stack
     }
 
+    pub fn downcast_ref<P>(&self) -> Option<&P>
+    where
+        P: Doom,
+    {
+        (&self.doom as &dyn Any)
+            .downcast_ref::<P>()
+            .or_else(|| self.stack.downcast_ref::<P>())
+    }
+
     pub fn push<P>(self, doom: P) -> Top<P>
     where
         P: Doom,
@@ -71,9 +103,20 @@ where
             doom: top,
             location,
             stack,
+            #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+            backtrace,
         } = top;
 
-        let stack = stack.push_as_stack(top);
+        // Archives `top` reusing the `Backtrace` already captured when the `Top` was created
+        // (if any), rather than letting `Entry::archive` capture a new one at this conversion
+        // site: the latter would make the `Entry`'s backtrace point at wherever the `Top` was
+        // converted/propagated instead of wherever the error actually occurred.
+        #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+        let entry = Entry::archive_with_backtrace(top, backtrace);
+        #[cfg(not(all(feature = "backtrace", not(feature = "no_std"))))]
+        let entry = Entry::archive(top);
+
+        let stack = stack.push_entry(entry);
 
         if let Some(location) = location {
             stack.spot(location)
@@ -83,7 +126,45 @@ where
     }
 }
 
-impl<D> Error for Top<D> where D: Doom {}
+/// A [`Top`]'s [`Error::source()`] is its [`Stack`] (when non-empty), so walking it further
+/// yields one node per archived [`Entry`], exactly as [`Error::source()`] on a [`Stack`] does.
+///
+/// # Examples
+///
+/// ```
+/// use doomstack::{Description, Doom};
+/// use std::error::Error;
+///
+/// struct Oupsie;
+///
+/// impl Doom for Oupsie {
+///     fn tag(&self) -> &'static str {
+///         "Oupsie"
+///     }
+///
+///     fn description(&self) -> Description {
+///         Description::Static("Made a mess")
+///     }
+/// }
+///
+/// let top = Oupsie.into_stack().push(Oupsie);
+/// assert!(Error::source(&top).is_some());
+///
+/// let top_without_stack = Oupsie.into_top();
+/// assert!(Error::source(&top_without_stack).is_none());
+/// ```
+impl<D> Error for Top<D>
+where
+    D: Doom,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        if self.stack.is_empty() {
+            None
+        } else {
+            Some(&self.stack)
+        }
+    }
+}
 
 impl<D> Display for Top<D>
 where
@@ -111,6 +192,11 @@ where
             writeln!(f, "[{}] {}", self.doom.tag(), self.doom.description())?;
         }
 
+        #[cfg(all(feature = "backtrace", not(feature = "no_std")))]
+        if let Some(backtrace) = self.backtrace() {
+            writeln!(f, "{backtrace}")?;
+        }
+
         write!(f, "{:?}", self.stack)
     }
 }